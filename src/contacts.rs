@@ -12,6 +12,12 @@ pub trait StringExt {
     /// Checks if the string is a phone number.
     /// Returns None or the String
     fn is_phone(&self) -> Option<String>;
+    /// Returns the canonicalized form of the string if it is an email address.
+    /// See `normalize_email` for the canonicalization rules.
+    fn normalized_email(&self) -> Option<String>;
+    /// Checks if the string is a URL.
+    /// Returns None or the String
+    fn is_url(&self) -> Option<String>;
 }
 
 /// Extend std::String/std::&str to easily call an <Option> is_email or is_phone_number check
@@ -29,6 +35,20 @@ impl StringExt for &str {
         }
         None
     }
+
+    fn normalized_email(&self) -> Option<String> {
+        if strict_email(self) {
+            return normalize_email(self);
+        }
+        None
+    }
+
+    fn is_url(&self) -> Option<String> {
+        if valid_url(self) {
+            return Some(self.to_string());
+        }
+        None
+    }
 }
 
 /// find_emails accepts some source &str and returns a vector of all
@@ -43,9 +63,11 @@ pub fn find_emails(source: &str) -> Vec<String> {
     linkify_emails.dedup();
     emails.append(&mut linkify_emails);
     emails.sort();
-    for email in &mut emails {
-        *email = email.to_lowercase();
-    }
+    let mut emails: Vec<String> = emails
+        .iter()
+        .filter_map(|email| normalize_email(email))
+        .collect();
+    emails.sort();
     emails.dedup();
     emails
 }
@@ -62,61 +84,368 @@ fn double_check_emails(source: &str) -> Vec<String> {
     emails
 }
 
-/// find_phone_nums goes through every str in the argument and performs
-/// simple regex to evalute if they are potentially a phone number
-/// returns Vec<String> of results
+/// find_phone_nums scans source for phone-number-shaped substrings (digits
+/// plus the usual `+`/space/dash/paren separators), parses each one, and
+/// returns their E.164 form so that differently-formatted dials of the
+/// same number dedup to a single result.
 pub fn find_phone_nums(source: &str) -> Vec<String> {
-    let mut phone_nums: Vec<String> = source
-        .split_whitespace()
-        .filter_map(|word| word.is_phone())
+    let mut phone_nums: Vec<String> = phone_candidates(source)
+        .iter()
+        .filter_map(|candidate| parse_phone(candidate, None))
+        .map(|phone| phone.e164())
         .collect();
     phone_nums.sort();
     phone_nums.dedup();
     phone_nums
 }
 
+/// Sweeps source for runs of digits and phone-number punctuation, the way
+/// `double_check_emails` sweeps for emails with `LinkFinder`.
+fn phone_candidates(source: &str) -> Vec<String> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"\+?\d[\d\s.\-()]{6,}\d").unwrap();
+    }
+    RE.find_iter(source)
+        .map(|m| m.as_str().trim().to_string())
+        .collect()
+}
+
+/// find_urls accepts some source &str and returns a vector of all
+/// potential URLs, as std::Strings, normalized and deduped.
+pub fn find_urls(source: &str) -> Vec<String> {
+    let mut link_finder = LinkFinder::new();
+    link_finder.kinds(&[LinkKind::Url]);
+    let mut urls: Vec<String> = link_finder
+        .links(source)
+        .map(|link| normalize_url(link.as_str()))
+        .collect();
+    urls.sort();
+    urls.dedup();
+    urls
+}
+
+/// find_urls_matching is like `find_urls`, but keeps only the URLs whose
+/// path, together with any query string and fragment after it, matches
+/// `pattern`, a path template such as `/users/:id/posts/*`.
+/// Literal segments must match exactly, `:name` segments match a single
+/// path segment, and `*` matches the remainder of the path.
+pub fn find_urls_matching(source: &str, pattern: &str) -> Vec<String> {
+    let re = match compile_url_pattern(pattern) {
+        Some(re) => re,
+        None => return Vec::new(),
+    };
+    find_urls(source)
+        .into_iter()
+        .filter(|url| re.is_match(url_path(url)))
+        .collect()
+}
+
+/// Checks whether `text`, taken as a whole, is a URL.
+fn valid_url(text: &str) -> bool {
+    let mut link_finder = LinkFinder::new();
+    link_finder.kinds(&[LinkKind::Url]);
+    link_finder.links(text).any(|link| link.as_str() == text)
+}
+
+/// Normalizes a URL by lowercasing its scheme and host (the path, query,
+/// and fragment are left as-is, since they can be case-sensitive) and
+/// stripping trailing punctuation `linkify` sometimes leaves attached,
+/// like a sentence-ending period or a closing paren.
+fn normalize_url(url: &str) -> String {
+    let trimmed = url.trim_end_matches(|c: char| ".,;:!?)]}'\"".contains(c));
+    match trimmed.find("://") {
+        Some(scheme_end) => {
+            let scheme = &trimmed[..scheme_end];
+            let after_scheme = &trimmed[scheme_end + 3..];
+            let host_end = after_scheme
+                .find(['/', '?', '#'])
+                .unwrap_or(after_scheme.len());
+            let host = &after_scheme[..host_end];
+            let rest = &after_scheme[host_end..];
+            format!("{}://{}{}", scheme.to_lowercase(), host.to_lowercase(), rest)
+        }
+        None => trimmed.to_string(),
+    }
+}
+
+/// Extracts the path (and anything after it) from a URL, or `"/"` if the
+/// URL has no path beyond its host.
+fn url_path(url: &str) -> &str {
+    match url.find("://") {
+        Some(scheme_end) => {
+            let after_scheme = &url[scheme_end + 3..];
+            match after_scheme.find('/') {
+                Some(slash) => &after_scheme[slash..],
+                None => "/",
+            }
+        }
+        None => url,
+    }
+}
+
+/// Compiles a path template like `/users/:id/posts/*` into a regex
+/// anchored at both ends: literal segments are escaped, `:name` segments
+/// become `[^/]+` capture groups, and `*` becomes `.*`.
+fn compile_url_pattern(pattern: &str) -> Option<Regex> {
+    let segments: Vec<String> = pattern
+        .split('/')
+        .map(|segment| {
+            if let Some(name) = segment.strip_prefix(':') {
+                format!("(?P<{}>[^/]+)", name)
+            } else if segment == "*" {
+                ".*".to_string()
+            } else {
+                regex::escape(segment)
+            }
+        })
+        .collect();
+    Regex::new(&format!("^{}$", segments.join("/"))).ok()
+}
+
+/// Canonicalizes an email address so that provider-specific quirks collapse
+/// to a single form. Splits `text` at the last `@` and lowercases both the
+/// local-part and domain. For `gmail.com`/`googlemail.com` addresses, any
+/// `+tag` subaddress is stripped from the local-part, all `.` characters
+/// are removed from the local-part, and the domain is rewritten to
+/// `gmail.com`. Returns None if `text` contains no `@`.
+fn normalize_email(text: &str) -> Option<String> {
+    let at = text.rfind('@')?;
+    let local = text[..at].to_lowercase();
+    let domain = text[at + 1..].to_lowercase();
+
+    if domain == "gmail.com" || domain == "googlemail.com" {
+        let local = match local.find('+') {
+            Some(tag) => &local[..tag],
+            None => &local,
+        };
+        let local = local.replace('.', "");
+        Some(format!("{}@gmail.com", local))
+    } else {
+        Some(format!("{}@{}", local, domain))
+    }
+}
+
+/// A parsed, validated email address, per RFC 5321/5322.
+///
+/// Splitting on the local-part/domain boundary (rather than matching the
+/// whole address with a single regex) lets callers pull out just the
+/// domain or local-part, and lets us enforce rules a regex struggles with,
+/// like rejecting leading/trailing/consecutive dots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailAddress {
+    pub local_part: String,
+    pub domain: String,
+}
+
+impl EmailAddress {
+    /// Parses `text` as an RFC 5321/5322 address, returning the split
+    /// local-part and domain on success.
+    ///
+    /// Accepts dot-atom local-parts (`firstname.lastname`, `first+tag`,
+    /// `_______`) and quoted local-parts (`"john doe"@example.com`),
+    /// together with multi-label domains (`subdomain.example.com`) and
+    /// IP-literal domains (`[123.123.123.123]`). Rejects empty
+    /// local-parts, leading/trailing/consecutive dots in a dot-atom
+    /// local-part, and multiple unescaped `@`.
+    pub fn parse(text: &str) -> Option<EmailAddress> {
+        let (local_part, domain) = split_local_and_domain(text)?;
+        if !is_valid_local_part(local_part) || !is_valid_domain(domain) {
+            return None;
+        }
+        Some(EmailAddress {
+            local_part: local_part.to_string(),
+            domain: domain.to_string(),
+        })
+    }
+}
+
+/// Splits `text` into `(local_part, domain)` at the `@` that separates
+/// them, respecting a quoted local-part so an `@` inside quotes doesn't
+/// count. Returns None if there isn't exactly one unquoted `@`.
+fn split_local_and_domain(text: &str) -> Option<(&str, &str)> {
+    if let Some(after_quote) = text.strip_prefix('"') {
+        let closing_quote = after_quote.find('"')? + 1;
+        let rest = &text[closing_quote + 1..];
+        let domain = rest.strip_prefix('@')?;
+        if domain.is_empty() || domain.contains('@') {
+            return None;
+        }
+        Some((&text[..=closing_quote], domain))
+    } else {
+        if text.matches('@').count() != 1 {
+            return None;
+        }
+        let at = text.find('@')?;
+        Some((&text[..at], &text[at + 1..]))
+    }
+}
+
+/// Validates a local-part, either quoted (`"..."`, contents unchecked
+/// beyond the enclosing quotes) or dot-atom (dot-separated atext labels,
+/// none of which may be empty).
+fn is_valid_local_part(local_part: &str) -> bool {
+    if local_part.starts_with('"') && local_part.ends_with('"') && local_part.len() >= 2 {
+        return true;
+    }
+    !local_part.is_empty()
+        && local_part
+            .split('.')
+            .all(|label| !label.is_empty() && label.chars().all(is_atext))
+}
+
+/// RFC 5322 `atext`: letters, digits, and a handful of punctuation marks.
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c)
+}
+
+/// Validates a domain, either an IP-literal (`[1.2.3.4]`) or a sequence
+/// of at least two dot-separated, hyphen-free-at-the-edges labels.
+fn is_valid_domain(domain: &str) -> bool {
+    if let Some(literal) = domain.strip_prefix('[').and_then(|d| d.strip_suffix(']')) {
+        return is_valid_ip_literal(literal);
+    }
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    labels.len() >= 2 && labels.iter().all(|label| is_valid_domain_label(label))
+}
+
+fn is_valid_domain_label(label: &str) -> bool {
+    !label.is_empty()
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+fn is_valid_ip_literal(literal: &str) -> bool {
+    let octets: Vec<&str> = literal.split('.').collect();
+    octets.len() == 4
+        && octets.iter().all(|octet| {
+            !octet.is_empty()
+                && octet.chars().all(|c| c.is_ascii_digit())
+                && octet.parse::<u16>().is_ok_and(|n| n <= 255)
+        })
+}
+
 /// Is it an email?
 fn strict_email(text: &str) -> bool {
-    if text.chars().filter(|&c| c == '@').count() > 1 {
-        return false;
+    EmailAddress::parse(text).is_some()
+}
+
+/// A parsed phone number, split into its country code and national
+/// significant number so it can be re-rendered in E.164 form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneNumber {
+    pub country_code: String,
+    pub national_number: String,
+}
+
+impl PhoneNumber {
+    /// Renders the number in E.164 form: `+` followed by the country
+    /// code and national number, with no spaces, dashes, or parens.
+    pub fn e164(&self) -> String {
+        format!("+{}{}", self.country_code, self.national_number)
     }
+}
 
-    lazy_static! {
-        static ref RE: Regex = Regex::new(
-            r"(?x)
-            @
-            [[:word:]]+
-            \.
-            [[:word:]]+$
-            "
-        )
-        .unwrap();
-    }
-    if RE.is_match(text) {
-        return true;
+/// Calling codes that are exactly one digit. Everything else is either
+/// two digits (`TWO_DIGIT_COUNTRY_CODES`) or, per the ITU-T E.164
+/// allocation, three digits.
+const ONE_DIGIT_COUNTRY_CODES: &[&str] = &["1", "7"];
+
+/// A sampling of the two-digit calling codes; not exhaustive, but enough
+/// to tell a two-digit code apart from a three-digit one in practice.
+const TWO_DIGIT_COUNTRY_CODES: &[&str] = &[
+    "20", "27", "30", "31", "32", "33", "34", "36", "39", "40", "41", "43", "44", "45", "46", "47",
+    "48", "49", "51", "52", "53", "54", "55", "56", "57", "58", "60", "61", "62", "63", "64", "65",
+    "66", "81", "82", "84", "86", "90", "91", "92", "93", "94", "95", "98",
+];
+
+/// Parses `text` as a phone number. If it starts with a leading `+`, the
+/// country code (1-3 digits) is read off per the E.164 allocation above.
+/// Otherwise, `default_region` supplies the country code and the
+/// region's own structural rule validates the national number (for
+/// example NANP's area/exchange/subscriber shape).
+pub fn parse_phone(text: &str, default_region: Option<&str>) -> Option<PhoneNumber> {
+    let text = text.trim();
+    let (country_code, national_number) = if let Some(rest) = text.strip_prefix('+') {
+        let digits: String = rest.chars().filter(char::is_ascii_digit).collect();
+        split_country_code(&digits)?
+    } else {
+        let digits: String = text.chars().filter(char::is_ascii_digit).collect();
+        let country_code = region_country_code(default_region.unwrap_or("US"))?;
+        (country_code.to_string(), strip_nanp_trunk_prefix(&digits).to_string())
     };
-    false
+
+    if !is_valid_national_number(&country_code, &national_number) {
+        return None;
+    }
+
+    Some(PhoneNumber {
+        country_code,
+        national_number,
+    })
 }
 
-/// Simple regex check for phone numbers
-fn valid_phone(text: &str) -> bool {
+/// Splits a run of digits (no leading `+`) into `(country_code, national_number)`.
+fn split_country_code(digits: &str) -> Option<(String, String)> {
+    if digits.len() < 2 {
+        return None;
+    }
+    if ONE_DIGIT_COUNTRY_CODES.contains(&&digits[..1]) {
+        return Some((digits[..1].to_string(), digits[1..].to_string()));
+    }
+    if digits.len() >= 3 && TWO_DIGIT_COUNTRY_CODES.contains(&&digits[..2]) {
+        return Some((digits[..2].to_string(), digits[2..].to_string()));
+    }
+    if digits.len() >= 4 {
+        return Some((digits[..3].to_string(), digits[3..].to_string()));
+    }
+    None
+}
+
+/// Maps a default region to its country code. Only NANP regions are
+/// recognized for now; other regions fall back to the `+<code>` form.
+fn region_country_code(region: &str) -> Option<&'static str> {
+    match region.to_uppercase().as_str() {
+        "US" | "CA" => Some("1"),
+        _ => None,
+    }
+}
+
+/// Drops a NANP trunk prefix (`1` in front of an 11-digit dial string) so
+/// the remainder is the 10-digit national significant number.
+fn strip_nanp_trunk_prefix(digits: &str) -> &str {
+    if digits.len() == 11 && digits.starts_with('1') {
+        &digits[1..]
+    } else {
+        digits
+    }
+}
+
+/// Validates a national number against its region's structural rule.
+/// NANP numbers get the area/exchange/subscriber check the old global
+/// regex used to apply to everything; other regions just require a
+/// non-empty national number.
+fn is_valid_national_number(country_code: &str, national_number: &str) -> bool {
+    match country_code {
+        "1" => is_valid_nanp(national_number),
+        _ => !national_number.is_empty(),
+    }
+}
+
+/// NANP structural validation: a 3-digit area code and exchange code
+/// (both with a leading digit of 2-9) followed by a 4-digit subscriber
+/// number.
+fn is_valid_nanp(national_number: &str) -> bool {
     lazy_static! {
-        static ref RE: Regex = Regex::new(
-            r"(?x)
-            (?:\+?1)?                       # Country Code Optional
-            [\s\.]?
-            (([2-9]\d{2})|\(([2-9]\d{2})\)) # Area Code
-            [\s\.\-]?
-            ([2-9]\d{2})                    # Exchange Code
-            [\s\.\-]?
-            (\d{4})                         # Subscriber Number"
-        )
-        .unwrap();
-    }
-    if RE.is_match(text) {
-        return true;
+        static ref RE: Regex = Regex::new(r"^[2-9]\d{2}[2-9]\d{2}\d{4}$").unwrap();
     }
-    false
+    RE.is_match(national_number)
+}
+
+/// Simple phone number check, built on the structured parser.
+fn valid_phone(text: &str) -> bool {
+    parse_phone(text, None).is_some()
 }
 // TODO move these out into a separate test file
 #[cfg(test)]
@@ -140,10 +469,36 @@ mod tests {
         assert_eq!(super::strict_email("my.email+1@example.com"), true);
         assert_eq!(super::strict_email("fname1202@domain.com"), true);
         assert_eq!(super::strict_email("user%example.com@example.org"), true);
-        assert_eq!(super::strict_email("@example.com"), true);
+        assert_eq!(super::strict_email("@example.com"), false);
         assert_eq!(super::strict_email("wrong@email@example.com"), false);
     }
 
+    #[test]
+    fn email_address_accepts_rfc_forms() {
+        use super::EmailAddress;
+
+        assert!(EmailAddress::parse("firstname.lastname@example.com").is_some());
+        assert!(EmailAddress::parse("first+tag@example.com").is_some());
+        assert!(EmailAddress::parse("_______@example.com").is_some());
+        assert!(EmailAddress::parse("\"john doe\"@example.com").is_some());
+        assert!(EmailAddress::parse("email@subdomain.example.com").is_some());
+
+        let ip_literal = EmailAddress::parse("email@[123.123.123.123]").unwrap();
+        assert_eq!(ip_literal.local_part, "email");
+        assert_eq!(ip_literal.domain, "[123.123.123.123]");
+    }
+
+    #[test]
+    fn email_address_rejects_malformed_forms() {
+        use super::EmailAddress;
+
+        assert_eq!(EmailAddress::parse("@example.com"), None);
+        assert_eq!(EmailAddress::parse("first..last@example.com"), None);
+        assert_eq!(EmailAddress::parse(".first@example.com"), None);
+        assert_eq!(EmailAddress::parse("first.@example.com"), None);
+        assert_eq!(EmailAddress::parse("wrong@email@example.com"), None);
+    }
+
     #[test]
     fn no_email_duplicates() {
         let sample = "hello my email is frank.roosevelt@whitehouse.gov, one more time that is frank.roosevelt@whitehouse.gov.  Just to be sure... frank.roosevelt@whitehouse.gov";
@@ -156,6 +511,63 @@ mod tests {
         assert_eq!(case_emails.len(), 1);
     }
 
+    #[test]
+    fn normalizes_gmail_quirks() {
+        assert_eq!(
+            super::normalize_email("john.doe+news@googlemail.com"),
+            Some("johndoe@gmail.com".to_string())
+        );
+        assert_eq!(
+            super::normalize_email("JohnDoe@gmail.com"),
+            Some("johndoe@gmail.com".to_string())
+        );
+        // already-normalized addresses are unchanged (idempotent)
+        assert_eq!(
+            super::normalize_email("johndoe@gmail.com"),
+            Some("johndoe@gmail.com".to_string())
+        );
+        assert_eq!(super::normalize_email("no-at-sign"), None);
+    }
+
+    #[test]
+    fn gmail_quirks_dedupe_in_find_emails() {
+        let sample = "reach me at john.doe+news@googlemail.com or JohnDoe@gmail.com";
+        let emails = super::find_emails(&sample);
+        assert_eq!(emails, vec!["johndoe@gmail.com".to_string()]);
+    }
+
+    // url tests
+    #[test]
+    fn should_not_be_url() {
+        assert_eq!("hello".is_url(), None);
+        assert_eq!("hello again".is_url(), None)
+    }
+
+    #[test]
+    fn should_be_url() {
+        assert_eq!(
+            "https://example.com".is_url(),
+            Some("https://example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn no_url_duplicates_and_normalized() {
+        let sample = "Visit HTTPS://Example.com/Path. Or https://example.com/Path again.";
+        let urls = super::find_urls(sample);
+        assert_eq!(urls, vec!["https://example.com/Path".to_string()]);
+    }
+
+    #[test]
+    fn find_urls_matching_route_template() {
+        let sample = "see /users/42/posts/7 at https://example.com/users/42/posts/7 or https://example.com/about";
+        let urls = super::find_urls_matching(sample, "/users/:id/posts/*");
+        assert_eq!(
+            urls,
+            vec!["https://example.com/users/42/posts/7".to_string()]
+        );
+    }
+
     // phone number tests
     #[test]
     fn valid_phone_number() {
@@ -176,4 +588,22 @@ mod tests {
             .iter()
             .for_each(|n| assert_eq!(super::valid_phone(n), false));
     }
+
+    #[test]
+    fn parse_phone_normalizes_to_e164() {
+        let nanp = super::parse_phone("1 (800) 233-2010", None).unwrap();
+        assert_eq!(nanp.country_code, "1");
+        assert_eq!(nanp.e164(), "+18002332010");
+
+        let international = super::parse_phone("+86 800 555 1234", None).unwrap();
+        assert_eq!(international.country_code, "86");
+        assert_eq!(international.e164(), "+868005551234");
+    }
+
+    #[test]
+    fn phone_dedupe_on_e164() {
+        let sample = "Call 1 (800) 233-2010 or +18002332010 for support.";
+        let nums = super::find_phone_nums(sample);
+        assert_eq!(nums, vec!["+18002332010".to_string()]);
+    }
 }
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate lazy_static;
+
+pub mod contacts;
+#[cfg(feature = "verify")]
+pub mod verify;
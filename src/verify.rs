@@ -0,0 +1,204 @@
+//! Optional SMTP/MX deliverability verification for extracted emails.
+//!
+//! This is deliberately layered: syntax is checked with the same
+//! `EmailAddress` parser `contacts` uses, then (network access required)
+//! the domain's MX records are resolved, then (still network) an SMTP
+//! conversation probes whether the mailbox is accepted, without ever
+//! sending a message. Everything past syntax needs real dependencies, so
+//! this whole module lives behind the `verify` cargo feature and the
+//! core crate stays dependency-light without it.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use lettre::transport::smtp::client::SmtpConnection;
+use lettre::transport::smtp::commands::{Mail, Rcpt};
+use lettre::transport::smtp::extension::ClientId;
+use lettre::transport::smtp::SMTP_PORT;
+use trust_dns_resolver::config::{ResolverConfig, ResolverOpts};
+use trust_dns_resolver::error::ResolveErrorKind;
+use trust_dns_resolver::Resolver;
+
+use crate::contacts::EmailAddress;
+
+/// Deliverability status for a single address, from the cheapest check
+/// (syntax) to the most expensive (an SMTP handshake).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailStatus {
+    /// The address doesn't parse per RFC 5321/5322.
+    Invalid,
+    /// The address's domain has no MX records to deliver to.
+    NoMxRecords,
+    /// None of the domain's MX hosts would accept a connection.
+    Unreachable,
+    /// An MX host accepted `MAIL FROM`/`RCPT TO` for this address.
+    Reachable,
+    /// The domain is deliverable in principle, but the mailbox itself
+    /// couldn't be confirmed (e.g. the server accepts all recipients, it
+    /// rejected the probe for a reason unrelated to validity, or MX
+    /// resolution itself failed transiently rather than coming back empty).
+    Unknown,
+}
+
+/// Configuration for an SMTP deliverability probe.
+#[derive(Debug, Clone)]
+pub struct VerifyConfig {
+    /// How long to wait on DNS and SMTP operations before giving up.
+    pub timeout: Duration,
+    /// The address used as the `MAIL FROM` sender identity.
+    pub sender: String,
+}
+
+impl Default for VerifyConfig {
+    fn default() -> Self {
+        VerifyConfig {
+            timeout: Duration::from_secs(10),
+            sender: "verify@localhost".to_string(),
+        }
+    }
+}
+
+/// Checks each address in `emails` for plausible deliverability, in
+/// stages: syntax, then MX records, then an SMTP `RCPT TO` probe.
+pub fn verify_emails(emails: &[String], config: VerifyConfig) -> Vec<EmailStatus> {
+    emails
+        .iter()
+        .map(|email| verify_email(email, &config))
+        .collect()
+}
+
+fn verify_email(email: &str, config: &VerifyConfig) -> EmailStatus {
+    let address = match EmailAddress::parse(email) {
+        Some(address) => address,
+        None => return EmailStatus::Invalid,
+    };
+
+    let resolver = match build_resolver(config) {
+        Some(resolver) => resolver,
+        None => return EmailStatus::Unknown,
+    };
+
+    let mx_hosts = match resolve_mx_hosts(&resolver, &address.domain) {
+        MxLookup::Hosts(hosts) => hosts,
+        MxLookup::NoRecords => return EmailStatus::NoMxRecords,
+        MxLookup::LookupFailed => return EmailStatus::Unknown,
+    };
+
+    probe_mailbox(&resolver, &mx_hosts, email, config)
+}
+
+/// Outcome of looking up a domain's MX records, keeping a failed lookup
+/// (DNS down, timed out, ...) distinct from a lookup that succeeded but
+/// came back empty.
+enum MxLookup {
+    Hosts(Vec<String>),
+    NoRecords,
+    LookupFailed,
+}
+
+/// Builds a resolver whose DNS timeout tracks `config.timeout`, shared by
+/// the MX lookup and the MX host address lookup so neither can hang past
+/// the configured deadline.
+fn build_resolver(config: &VerifyConfig) -> Option<Resolver> {
+    let mut opts = ResolverOpts::default();
+    opts.timeout = config.timeout;
+    Resolver::new(ResolverConfig::default(), opts).ok()
+}
+
+/// Resolves the MX hosts for `domain` via `resolver`, ordered by
+/// preference (lowest first, as the protocol prefers).
+fn resolve_mx_hosts(resolver: &Resolver, domain: &str) -> MxLookup {
+    let response = match resolver.mx_lookup(domain) {
+        Ok(response) => response,
+        Err(err) => {
+            return match err.kind() {
+                ResolveErrorKind::NoRecordsFound { .. } => MxLookup::NoRecords,
+                _ => MxLookup::LookupFailed,
+            }
+        }
+    };
+
+    let mut records: Vec<_> = response.iter().collect();
+    if records.is_empty() {
+        return MxLookup::NoRecords;
+    }
+    records.sort_by_key(|mx| mx.preference());
+    MxLookup::Hosts(records.iter().map(|mx| mx.exchange().to_utf8()).collect())
+}
+
+/// Resolves `host`'s address through `resolver`, so the lookup honors
+/// `config.timeout` instead of blocking on the unbounded system resolver.
+fn resolve_host_addr(resolver: &Resolver, host: &str) -> Option<SocketAddr> {
+    let response = resolver.lookup_ip(host).ok()?;
+    let ip = response.iter().next()?;
+    Some(SocketAddr::new(ip, SMTP_PORT))
+}
+
+/// Tries each MX host in order, issuing `MAIL FROM`/`RCPT TO` for
+/// `email` without ever sending `DATA`, and returns the first
+/// conclusive result.
+fn probe_mailbox(
+    resolver: &Resolver,
+    mx_hosts: &[String],
+    email: &str,
+    config: &VerifyConfig,
+) -> EmailStatus {
+    let hello = ClientId::Domain("localhost".to_string());
+    let sender: lettre::Address = match config.sender.parse() {
+        Ok(sender) => sender,
+        Err(_) => return EmailStatus::Unknown,
+    };
+    let recipient: lettre::Address = match email.parse() {
+        Ok(recipient) => recipient,
+        Err(_) => return EmailStatus::Invalid,
+    };
+
+    let mut reached_a_host = false;
+    for host in mx_hosts {
+        let addr = match resolve_host_addr(resolver, host) {
+            Some(addr) => addr,
+            None => continue,
+        };
+
+        let mut connection =
+            match SmtpConnection::connect(addr, Some(config.timeout), &hello, None, None) {
+                Ok(connection) => connection,
+                Err(_) => continue,
+            };
+        reached_a_host = true;
+
+        let mail_accepted = connection
+            .command(Mail::new(Some(sender.clone()), vec![]))
+            .map(|response| response.is_positive())
+            .unwrap_or(false);
+        if !mail_accepted {
+            continue;
+        }
+
+        let rcpt_accepted = connection
+            .command(Rcpt::new(recipient.clone(), vec![]))
+            .map(|response| response.is_positive())
+            .unwrap_or(false);
+        if rcpt_accepted {
+            return EmailStatus::Reachable;
+        }
+        // Otherwise keep trying the remaining MX hosts before giving up.
+    }
+
+    if reached_a_host {
+        EmailStatus::Unknown
+    } else {
+        EmailStatus::Unreachable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_address_is_invalid_without_network() {
+        let config = VerifyConfig::default();
+        assert_eq!(verify_email("not-an-email", &config), EmailStatus::Invalid);
+    }
+}